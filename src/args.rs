@@ -15,6 +15,16 @@ pub struct EncodeArgs {
     pub chunk_type: String,
     pub message: String,
     pub output_file_path: Option<PathBuf>,
+    /// Read the chunk payload from this file instead of `message`.
+    #[clap(long)]
+    pub from_file: Option<PathBuf>,
+    /// Treat `message` as base64 and decode it to bytes before storing it.
+    #[clap(long)]
+    pub base64: bool,
+    /// Store a `key=value` metadata field instead of a flat message. Can
+    /// be repeated to attach multiple fields (e.g. author, timestamp).
+    #[clap(long = "field")]
+    pub fields: Vec<String>,
 }
 
 #[derive(Args, Debug, PartialEq)]
@@ -53,6 +63,9 @@ mod test {
             chunk_type: "RuSt".to_string(),
             message: "Secret decoder ring".to_string(),
             output_file_path: None,
+            from_file: None,
+            base64: false,
+            fields: Vec::new(),
         });
         let cli = Cli::from_iter(vec![
             "pngme",
@@ -66,6 +79,57 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    pub fn test_encode_command_with_base64_flag() {
+        let expected = Commands::Encode(EncodeArgs {
+            input_file_path: PathBuf::from("/a/b/c"),
+            chunk_type: "RuSt".to_string(),
+            message: "U2VjcmV0IGRlY29kZXIgcmluZw==".to_string(),
+            output_file_path: None,
+            from_file: None,
+            base64: true,
+            fields: Vec::new(),
+        });
+        let cli = Cli::from_iter(vec![
+            "pngme",
+            "encode",
+            "/a/b/c",
+            "RuSt",
+            "U2VjcmV0IGRlY29kZXIgcmluZw==",
+            "--base64",
+        ]);
+        let actual = cli.command;
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    pub fn test_encode_command_with_repeated_field_flag() {
+        let expected = Commands::Encode(EncodeArgs {
+            input_file_path: PathBuf::from("/a/b/c"),
+            chunk_type: "RuSt".to_string(),
+            message: "Secret decoder ring".to_string(),
+            output_file_path: None,
+            from_file: None,
+            base64: false,
+            fields: vec!["author=Jane Doe".to_string(), "content-type=text/plain".to_string()],
+        });
+        let cli = Cli::from_iter(vec![
+            "pngme",
+            "encode",
+            "/a/b/c",
+            "RuSt",
+            "Secret decoder ring",
+            "--field",
+            "author=Jane Doe",
+            "--field",
+            "content-type=text/plain",
+        ]);
+        let actual = cli.command;
+
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     pub fn test_decode_command() {
         let expected = Commands::Decode(DecodeArgs {
@@ -0,0 +1,152 @@
+use crate::Result;
+use std::fmt::Display;
+
+const ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PAD: u8 = b'=';
+
+#[derive(Debug)]
+pub enum Base64Error {
+    InvalidLength(usize),
+    InvalidCharacter(char),
+    InvalidPadding,
+}
+
+impl std::error::Error for Base64Error {}
+
+impl Display for Base64Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Base64Error::InvalidLength(length) => write!(
+                f,
+                "Base64 input length must be a multiple of 4 but was {}",
+                length
+            ),
+            Base64Error::InvalidCharacter(c) => write!(f, "Invalid base64 character '{}'", c),
+            Base64Error::InvalidPadding => {
+                write!(f, "'=' padding may only appear as the last 1 or 2 characters of a 4-character group")
+            }
+        }
+    }
+}
+
+/// Encodes `data` using the standard base64 alphabet, padding the final
+/// group with `=` so the output length is always a multiple of 4.
+pub fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for group in data.chunks(3) {
+        let b0 = group[0];
+        let b1 = group.get(1).copied().unwrap_or(0);
+        let b2 = group.get(2).copied().unwrap_or(0);
+
+        out.push(ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+        out.push(if group.len() > 1 {
+            ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+        } else {
+            PAD as char
+        });
+        out.push(if group.len() > 2 {
+            ALPHABET[(b2 & 0x3f) as usize] as char
+        } else {
+            PAD as char
+        });
+    }
+    out
+}
+
+/// Decodes a standard base64 string, rejecting invalid characters and
+/// lengths rather than panicking.
+pub fn decode(input: &str) -> Result<Vec<u8>> {
+    let bytes = input.as_bytes();
+    if bytes.len() % 4 != 0 {
+        return Err(Box::new(Base64Error::InvalidLength(bytes.len())));
+    }
+    let group_count = bytes.len() / 4;
+    let mut out = Vec::with_capacity(group_count * 3);
+    for (i, group) in bytes.chunks(4).enumerate() {
+        let padding = group.iter().rev().take_while(|&&b| b == PAD).count();
+        let is_last_group = i == group_count - 1;
+        if padding > 2
+            || group[..group.len() - padding].contains(&PAD)
+            || (padding > 0 && !is_last_group)
+        {
+            return Err(Box::new(Base64Error::InvalidPadding));
+        }
+        let mut values = [0u8; 4];
+        for (i, &b) in group.iter().enumerate() {
+            if b != PAD {
+                values[i] = decode_char(b)?;
+            }
+        }
+        out.push((values[0] << 2) | (values[1] >> 4));
+        if padding < 2 {
+            out.push((values[1] << 4) | (values[2] >> 2));
+        }
+        if padding < 1 {
+            out.push((values[2] << 6) | values[3]);
+        }
+    }
+    Ok(out)
+}
+
+fn decode_char(b: u8) -> Result<u8> {
+    ALPHABET
+        .iter()
+        .position(|&c| c == b)
+        .map(|pos| pos as u8)
+        .ok_or_else(|| Box::new(Base64Error::InvalidCharacter(b as char)) as crate::MyError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_known_vector() {
+        assert_eq!(encode(b"Man"), "TWFu");
+        assert_eq!(encode(b"Ma"), "TWE=");
+        assert_eq!(encode(b"M"), "TQ==");
+    }
+
+    #[test]
+    fn test_decode_known_vector() {
+        assert_eq!(decode("TWFu").unwrap(), b"Man");
+        assert_eq!(decode("TWE=").unwrap(), b"Ma");
+        assert_eq!(decode("TQ==").unwrap(), b"M");
+    }
+
+    #[test]
+    fn test_round_trip_arbitrary_bytes() {
+        let data: Vec<u8> = (0..=255).collect();
+        let encoded = encode(&data);
+        assert_eq!(decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_length() {
+        assert!(decode("abc").is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_character() {
+        assert!(decode("T!Fu").is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_interior_padding() {
+        assert!(decode("T=Fu").is_err());
+        assert!(decode("TW=u").is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_all_padding_group() {
+        assert!(decode("====").is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_padding_before_the_final_group() {
+        assert!(decode("TQ==TWFu").is_err());
+        assert!(decode("TWE=TWFu").is_err());
+    }
+}
@@ -1,12 +1,14 @@
 use crate::chunk_type::ChunkType;
 use crate::{Error, Result};
-use crc::{Crc, CRC_32_ISO_HDLC};
+use crc::{Crc, Digest, CRC_32_ISO_HDLC};
 use std::fmt::Display;
 use std::io::{BufReader, Read};
 
-const MAXIMUM_LENGTH: u32 = (1 << 31) - 1;
+pub(crate) const MAXIMUM_LENGTH: u32 = (1 << 31) - 1;
+const PNG_SIGNATURE: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+static CHUNK_CRC: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
 
-struct Chunk {
+pub(crate) struct Chunk {
     length: u32,
     chunk_type: ChunkType,
     chunk_data: Vec<u8>,
@@ -65,7 +67,7 @@ impl TryFrom<&[u8]> for Chunk {
             .chain(chunk_data.iter())
             .copied()
             .collect();
-        let crc = Crc::<u32>::new(&CRC_32_ISO_HDLC).checksum(&bytes);
+        let crc = CHUNK_CRC.checksum(&bytes);
         if provided_crc != crc {
             return Err(ChunkDecodingError::boxed(format!("Bad crc given!")));
         }
@@ -80,7 +82,15 @@ impl TryFrom<&[u8]> for Chunk {
 
 impl Display for Chunk {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}\t{}", self.chunk_type, self.data_as_string().unwrap())
+        match self.data_as_string() {
+            Ok(s) => write!(f, "{}\t{}", self.chunk_type, s),
+            Err(_) => write!(
+                f,
+                "{}\t{}",
+                self.chunk_type,
+                crate::base64::encode(&self.chunk_data)
+            ),
+        }
     }
 }
 
@@ -92,7 +102,7 @@ impl Chunk {
             .chain(data.iter())
             .copied()
             .collect();
-        let crc = Crc::<u32>::new(&CRC_32_ISO_HDLC).checksum(&bytes);
+        let crc = CHUNK_CRC.checksum(&bytes);
         Self {
             length: data.len() as u32,
             chunk_type,
@@ -103,10 +113,10 @@ impl Chunk {
     fn length(&self) -> u32 {
         self.length
     }
-    fn chunk_type(&self) -> &ChunkType {
+    pub(crate) fn chunk_type(&self) -> &ChunkType {
         &self.chunk_type
     }
-    fn data(&self) -> &[u8] {
+    pub(crate) fn data(&self) -> &[u8] {
         &self.chunk_data
     }
     fn crc(&self) -> u32 {
@@ -127,6 +137,153 @@ impl Chunk {
     }
 }
 
+// The state a `ChunkReader` is in between calls to `next_chunk`.
+#[derive(Debug, PartialEq, Eq)]
+enum State {
+    Signature,
+    Length,
+    Type,
+    Data(u64),
+    Crc,
+}
+
+/// Pulls `Chunk`s one at a time out of any `Read` source instead of
+/// requiring the whole file to be buffered up front.
+///
+/// The PNG signature is consumed once, on the first call to `next_chunk`.
+/// A clean EOF between chunks ends iteration (returns `Ok(None)`); an EOF
+/// in the middle of a chunk is reported as a decoding error.
+pub struct ChunkReader<R: Read> {
+    reader: R,
+    state: State,
+    length: u32,
+    chunk_type: Option<ChunkType>,
+    data: Vec<u8>,
+    digest: Option<Digest<'static, u32>>,
+}
+
+impl<R: Read> ChunkReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            state: State::Signature,
+            length: 0,
+            chunk_type: None,
+            data: Vec::new(),
+            digest: None,
+        }
+    }
+
+    /// Fills `buf` completely, returning how many bytes were read before an
+    /// EOF was hit. A return value equal to `buf.len()` means `buf` was
+    /// filled; anything less means the source ended early.
+    fn read_up_to(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            match self.reader.read(&mut buf[filled..])? {
+                0 => break,
+                n => filled += n,
+            }
+        }
+        Ok(filled)
+    }
+
+    /// Reads `buf.len()` bytes, treating any short read as a decoding error.
+    fn read_exact_mid_chunk(&mut self, buf: &mut [u8]) -> Result<()> {
+        let filled = self.read_up_to(buf)?;
+        if filled != buf.len() {
+            return Err(ChunkDecodingError::boxed(format!(
+                "Unexpected EOF after {} of {} bytes",
+                filled,
+                buf.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Advances the state machine and returns the next complete chunk, or
+    /// `None` once the source is cleanly exhausted between chunks.
+    pub fn next_chunk(&mut self) -> Result<Option<Chunk>> {
+        loop {
+            match self.state {
+                State::Signature => {
+                    let mut signature = [0; 8];
+                    self.read_exact_mid_chunk(&mut signature)?;
+                    if signature != PNG_SIGNATURE {
+                        return Err(ChunkDecodingError::boxed(
+                            "Invalid PNG signature".to_string(),
+                        ));
+                    }
+                    self.state = State::Length;
+                }
+                State::Length => {
+                    let mut buf = [0; 4];
+                    let filled = self.read_up_to(&mut buf)?;
+                    if filled == 0 {
+                        return Ok(None);
+                    }
+                    if filled != buf.len() {
+                        return Err(ChunkDecodingError::boxed(format!(
+                            "Unexpected EOF after {} of {} bytes",
+                            filled,
+                            buf.len()
+                        )));
+                    }
+                    let length = u32::from_be_bytes(buf);
+                    if length > MAXIMUM_LENGTH {
+                        return Err(ChunkDecodingError::boxed(format!(
+                            "Length is too long ({} > 2^31 - 1)",
+                            length
+                        )));
+                    }
+                    self.length = length;
+                    self.state = State::Type;
+                }
+                State::Type => {
+                    let mut buf = [0; 4];
+                    self.read_exact_mid_chunk(&mut buf)?;
+                    let chunk_type = ChunkType::try_from(buf)?;
+                    let mut digest = CHUNK_CRC.digest();
+                    digest.update(&buf);
+                    self.chunk_type = Some(chunk_type);
+                    self.digest = Some(digest);
+                    self.data = Vec::with_capacity(self.length as usize);
+                    self.state = State::Data(self.length as u64);
+                }
+                State::Data(remaining) => {
+                    if remaining == 0 {
+                        self.state = State::Crc;
+                        continue;
+                    }
+                    let mut buf = [0; 8192];
+                    let to_read = remaining.min(buf.len() as u64) as usize;
+                    self.read_exact_mid_chunk(&mut buf[..to_read])?;
+                    self.digest.as_mut().unwrap().update(&buf[..to_read]);
+                    self.data.extend_from_slice(&buf[..to_read]);
+                    self.state = State::Data(remaining - to_read as u64);
+                }
+                State::Crc => {
+                    let mut buf = [0; 4];
+                    self.read_exact_mid_chunk(&mut buf)?;
+                    let provided_crc = u32::from_be_bytes(buf);
+                    let crc = self.digest.take().unwrap().finalize();
+                    if provided_crc != crc {
+                        return Err(ChunkDecodingError::boxed("Bad crc given!".to_string()));
+                    }
+                    let chunk = Chunk {
+                        length: self.length,
+                        chunk_type: self.chunk_type.take().unwrap(),
+                        chunk_data: std::mem::take(&mut self.data),
+                        crc,
+                    };
+                    self.state = State::Length;
+                    return Ok(Some(chunk));
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,4 +413,68 @@ mod tests {
 
         let _chunk_string = format!("{}", chunk);
     }
+
+    fn testing_png(chunks: &[Chunk]) -> Vec<u8> {
+        PNG_SIGNATURE
+            .iter()
+            .copied()
+            .chain(chunks.iter().flat_map(|c| c.as_bytes()))
+            .collect()
+    }
+
+    #[test]
+    fn test_chunk_reader_reads_one_chunk() {
+        let chunk = testing_chunk();
+        let png = testing_png(&[chunk]);
+
+        let mut reader = ChunkReader::new(png.as_slice());
+        let read_chunk = reader.next_chunk().unwrap().unwrap();
+        assert_eq!(read_chunk.chunk_type().to_string(), String::from("RuSt"));
+        assert_eq!(read_chunk.length(), 42);
+        assert_eq!(read_chunk.crc(), 2882656334);
+
+        assert!(reader.next_chunk().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_chunk_reader_reads_multiple_chunks() {
+        let first = testing_chunk();
+        let second = Chunk::new(
+            ChunkType::from_str("RuSt").unwrap(),
+            "a second message".as_bytes().to_vec(),
+        );
+        let png = testing_png(&[first, second]);
+
+        let mut reader = ChunkReader::new(png.as_slice());
+        let first_read = reader.next_chunk().unwrap().unwrap();
+        let second_read = reader.next_chunk().unwrap().unwrap();
+        assert_eq!(
+            first_read.data_as_string().unwrap(),
+            "This is where your secret message will be!"
+        );
+        assert_eq!(second_read.data_as_string().unwrap(), "a second message");
+        assert!(reader.next_chunk().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_chunk_reader_errors_on_eof_mid_chunk() {
+        let chunk = testing_chunk();
+        let png = testing_png(&[chunk]);
+        let truncated = &png[..png.len() - 4];
+
+        let mut reader = ChunkReader::new(truncated);
+        assert!(reader.next_chunk().is_err());
+    }
+
+    #[test]
+    fn test_display_falls_back_to_base64_for_non_utf8_data() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let data = vec![0xff, 0xfe, 0xfd];
+        let chunk = Chunk::new(chunk_type, data.clone());
+        assert!(chunk.data_as_string().is_err());
+        assert_eq!(
+            chunk.to_string(),
+            format!("RuSt\t{}", crate::base64::encode(&data))
+        );
+    }
 }
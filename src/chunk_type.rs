@@ -24,7 +24,7 @@ impl Display for ChunkTypeError {
     }
 }
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub struct ChunkType {
     raw_bytes: [u8; 4],
 }
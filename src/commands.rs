@@ -1,26 +1,98 @@
 use crate::args::*;
 use crate::png::Png;
-use crate::chunk::Chunk;
+use crate::chunk::{Chunk, ChunkReader, MAXIMUM_LENGTH};
 use crate::chunk_type::ChunkType;
+use crate::metadata::{MetadataChunk, MetadataField};
+use crate::multipart;
 use crate::Result;
 use std::fs;
+use std::io::{BufReader, Read};
+use std::path::Path;
 use std::str::FromStr;
 
+/// Opens `path` for streaming, treating `-` as stdin so `print`/`decode`
+/// can operate on piped input without holding it entirely in memory.
+fn open_input(path: &Path) -> Result<Box<dyn Read>> {
+    if path.as_os_str() == "-" {
+        Ok(Box::new(std::io::stdin()))
+    } else {
+        Ok(Box::new(fs::File::open(path)?))
+    }
+}
+
+/// Prints a decoded chunk's payload, rendering it as metadata when it
+/// parses as one and falling back to the chunk's own `Display` (text or
+/// base64) otherwise.
+fn render_decoded(chunk: &Chunk) {
+    match MetadataChunk::parse(chunk.data()) {
+        Ok(metadata) => println!("Decoded: {}", metadata),
+        Err(_) => println!("Decoded: {}", chunk),
+    }
+}
+
 fn encode(args: EncodeArgs) -> Result<()> {
     let input_bytes = fs::read(&args.input_file_path)?;
-    let output = args.output_file_path.unwrap_or(args.input_file_path);
+    let output = args.output_file_path.clone().unwrap_or(args.input_file_path.clone());
     let mut png = Png::try_from(input_bytes.as_slice())?;
-    let chunk = Chunk::new(ChunkType::from_str(args.chunk_type.as_str())?, args.message.as_bytes().to_vec());
-    png.append_chunk(chunk);
+    let payload = if !args.fields.is_empty() {
+        let mut metadata = MetadataChunk::new();
+        for field in &args.fields {
+            let parsed = match field.split_once('=') {
+                Some((key, value)) => crate::metadata::field_for_key_value(key, value),
+                None => MetadataField::Utf8String(field.clone()),
+            };
+            metadata.push(parsed);
+        }
+        metadata.to_bytes()
+    } else if let Some(from_file) = &args.from_file {
+        fs::read(from_file)?
+    } else if args.base64 {
+        crate::base64::decode(args.message.as_str())?
+    } else {
+        args.message.as_bytes().to_vec()
+    };
+    let chunk_type = ChunkType::from_str(args.chunk_type.as_str())?;
+    if payload.len() > MAXIMUM_LENGTH as usize {
+        for part in multipart::split(&payload) {
+            png.append_chunk(Chunk::new(chunk_type.clone(), part));
+        }
+    } else {
+        png.append_chunk(Chunk::new(chunk_type, payload));
+    }
     fs::write(output, png.as_bytes())?;
     Ok(())
 }
 
 fn decode(args: DecodeArgs) -> Result<()> {
-    let input_bytes = fs::read(&args.input_file_path)?;
-    let png = Png::try_from(input_bytes.as_slice())?;
-    if let Some(c) = png.chunk_by_type(args.chunk_type.as_str()) {
-        println!("Decoded: {}", c);
+    let mut reader = ChunkReader::new(BufReader::new(open_input(&args.input_file_path)?));
+    let mut matching: Vec<Chunk> = Vec::new();
+    while let Some(chunk) = reader.next_chunk()? {
+        if chunk.chunk_type().to_string() == args.chunk_type {
+            matching.push(chunk);
+        }
+    }
+    if matching.is_empty() {
+        return Ok(());
+    }
+    // Multiple chunks of one type (e.g. `IDAT`) are normal in an ordinary
+    // PNG, so only treat them as a split message if every one of them
+    // actually carries a `[sequence, total]` header.
+    let is_multipart = matching.len() > 1 && matching.iter().all(|c| multipart::has_header(c.data()));
+    if is_multipart {
+        let parts: Vec<Vec<u8>> = matching.iter().map(|c| c.data().to_vec()).collect();
+        let decoded = Chunk::new(matching[0].chunk_type().clone(), multipart::reassemble(parts)?);
+        render_decoded(&decoded);
+    } else {
+        if matching.len() > 1 {
+            eprintln!(
+                "Warning: found {} chunks of type {} with no multipart header; decoding each separately",
+                matching.len(),
+                args.chunk_type
+            );
+        }
+        for chunk in &matching {
+            render_decoded(chunk);
+        }
     }
     Ok(())
 }
@@ -38,10 +110,12 @@ fn remove(args: RemoveArgs) -> Result<()> {
     Ok(())
 }
 fn print(args: PrintArgs) -> Result<()> {
-    let input_bytes = fs::read(&args.input_file_path)?;
-    let png = Png::try_from(input_bytes.as_slice())?;
-    for chunk in png.chunks() {
-        println!("{}", chunk);
+    let mut reader = ChunkReader::new(BufReader::new(open_input(&args.input_file_path)?));
+    while let Some(chunk) = reader.next_chunk()? {
+        match MetadataChunk::parse(chunk.data()) {
+            Ok(metadata) => println!("{}\t{}", chunk.chunk_type(), metadata),
+            Err(_) => println!("{}", chunk),
+        }
     }
 
     Ok(())
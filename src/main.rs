@@ -1,10 +1,14 @@
 use clap::Parser;
 
 mod args;
+mod base64;
 mod chunk;
 mod chunk_type;
 mod commands;
+mod metadata;
+mod multipart;
 mod png;
+mod rlp;
 
 pub type MyError = Box<dyn std::error::Error>;
 pub type Result<T> = std::result::Result<T, MyError>;
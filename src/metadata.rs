@@ -0,0 +1,306 @@
+use crate::Result;
+use std::fmt::Display;
+
+const TAG_UTF8_STRING: u8 = 0x0c;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_UTC_TIME: u8 = 0x17;
+const TAG_SEQUENCE: u8 = 0x30;
+
+#[derive(Debug)]
+pub enum MetadataError {
+    UnexpectedEof,
+    ExpectedSequence(u8),
+    UnknownTag(u8),
+    LengthMismatch { declared: usize, actual: usize },
+}
+
+impl std::error::Error for MetadataError {}
+
+impl Display for MetadataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetadataError::UnexpectedEof => write!(f, "Unexpected end of metadata input"),
+            MetadataError::ExpectedSequence(tag) => {
+                write!(f, "Expected a SEQUENCE tag (0x30) but found {:#x}", tag)
+            }
+            MetadataError::UnknownTag(tag) => write!(f, "Unknown metadata field tag {:#x}", tag),
+            MetadataError::LengthMismatch { declared, actual } => write!(
+                f,
+                "SEQUENCE declared length {} but {} bytes of payload were consumed",
+                declared, actual
+            ),
+        }
+    }
+}
+
+/// A single typed field inside a `MetadataChunk`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataField {
+    Utf8String(String),
+    OctetString(Vec<u8>),
+    UtcTime(String),
+}
+
+impl MetadataField {
+    fn tag(&self) -> u8 {
+        match self {
+            MetadataField::Utf8String(_) => TAG_UTF8_STRING,
+            MetadataField::OctetString(_) => TAG_OCTET_STRING,
+            MetadataField::UtcTime(_) => TAG_UTC_TIME,
+        }
+    }
+
+    fn value_bytes(&self) -> Vec<u8> {
+        match self {
+            MetadataField::Utf8String(s) => s.as_bytes().to_vec(),
+            MetadataField::OctetString(b) => b.clone(),
+            MetadataField::UtcTime(s) => s.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl Display for MetadataField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetadataField::Utf8String(s) => write!(f, "{}", s),
+            MetadataField::OctetString(b) => write!(f, "{}", crate::base64::encode(b)),
+            MetadataField::UtcTime(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Maps a `--field key=value` CLI argument to a typed field. `timestamp`
+/// is stored as a `UtcTime`; every other key (`author`, `content-type`,
+/// or anything unrecognized) is stored as a `Utf8String`. Both variants
+/// keep the original `key=value` text as their value, so the key survives
+/// the round trip through [`MetadataField::Display`].
+pub fn field_for_key_value(key: &str, value: &str) -> MetadataField {
+    match key {
+        "timestamp" => MetadataField::UtcTime(format!("{}={}", key, value)),
+        _ => MetadataField::Utf8String(format!("{}={}", key, value)),
+    }
+}
+
+/// An ordered set of typed fields (author, timestamp, content-type, ...)
+/// stored as chunk data using an ASN.1 DER-style tag-length-value layout,
+/// wrapped in an outer SEQUENCE header.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetadataChunk {
+    fields: Vec<MetadataField>,
+}
+
+impl MetadataChunk {
+    pub fn new() -> Self {
+        Self { fields: Vec::new() }
+    }
+
+    pub fn push(&mut self, field: MetadataField) {
+        self.fields.push(field);
+    }
+
+    pub fn fields(&self) -> &[MetadataField] {
+        &self.fields
+    }
+
+    /// Serializes the fields into their DER-style TLV encoding, wrapped in
+    /// an outer SEQUENCE.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let payload: Vec<u8> = self
+            .fields
+            .iter()
+            .flat_map(|field| encode_tlv(field.tag(), &field.value_bytes()))
+            .collect();
+        let mut out = Vec::with_capacity(payload.len() + 5);
+        out.push(TAG_SEQUENCE);
+        out.extend(encode_der_length(payload.len()));
+        out.extend(payload);
+        out
+    }
+
+    /// Parses a `MetadataChunk` from chunk data, validating that every
+    /// field's declared length stays within bounds and that the outer
+    /// SEQUENCE length matches the bytes actually consumed.
+    pub fn parse(input: &[u8]) -> Result<Self> {
+        if input.is_empty() {
+            return Err(Box::new(MetadataError::UnexpectedEof));
+        }
+        if input[0] != TAG_SEQUENCE {
+            return Err(Box::new(MetadataError::ExpectedSequence(input[0])));
+        }
+        let (len, len_size) = decode_der_length(&input[1..])?;
+        let start = 1 + len_size;
+        require_len(input, start + len)?;
+        if start + len != input.len() {
+            return Err(Box::new(MetadataError::LengthMismatch {
+                declared: len,
+                actual: input.len() - start,
+            }));
+        }
+        let payload = &input[start..start + len];
+
+        let mut fields = Vec::new();
+        let mut offset = 0;
+        while offset < payload.len() {
+            let (field, consumed) = decode_tlv(&payload[offset..])?;
+            fields.push(field);
+            offset += consumed;
+        }
+        Ok(Self { fields })
+    }
+}
+
+impl Display for MetadataChunk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rendered: Vec<String> = self.fields.iter().map(|field| field.to_string()).collect();
+        write!(f, "{}", rendered.join(", "))
+    }
+}
+
+fn encode_tlv(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(value.len() + 5);
+    out.push(tag);
+    out.extend(encode_der_length(value.len()));
+    out.extend_from_slice(value);
+    out
+}
+
+fn decode_tlv(input: &[u8]) -> Result<(MetadataField, usize)> {
+    if input.is_empty() {
+        return Err(Box::new(MetadataError::UnexpectedEof));
+    }
+    let tag = input[0];
+    let (len, len_size) = decode_der_length(&input[1..])?;
+    let start = 1 + len_size;
+    require_len(input, start + len)?;
+    let value = &input[start..start + len];
+    let field = match tag {
+        TAG_UTF8_STRING => {
+            MetadataField::Utf8String(String::from_utf8(value.to_vec()).map_err(Box::new)?)
+        }
+        TAG_OCTET_STRING => MetadataField::OctetString(value.to_vec()),
+        TAG_UTC_TIME => {
+            MetadataField::UtcTime(String::from_utf8(value.to_vec()).map_err(Box::new)?)
+        }
+        _ => return Err(Box::new(MetadataError::UnknownTag(tag))),
+    };
+    Ok((field, start + len))
+}
+
+/// Encodes a DER length: values under 128 are a single byte; otherwise
+/// the first byte is `0x80 | number_of_length_bytes` followed by the
+/// big-endian length.
+fn encode_der_length(len: usize) -> Vec<u8> {
+    if len < 128 {
+        vec![len as u8]
+    } else {
+        let bytes = (len as u64).to_be_bytes();
+        let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len() - 1);
+        let len_bytes = &bytes[first_nonzero..];
+        let mut out = Vec::with_capacity(1 + len_bytes.len());
+        out.push(0x80 | len_bytes.len() as u8);
+        out.extend_from_slice(len_bytes);
+        out
+    }
+}
+
+fn decode_der_length(input: &[u8]) -> Result<(usize, usize)> {
+    if input.is_empty() {
+        return Err(Box::new(MetadataError::UnexpectedEof));
+    }
+    let first = input[0];
+    if first < 0x80 {
+        Ok((first as usize, 1))
+    } else {
+        let num_bytes = (first & 0x7f) as usize;
+        require_len(input, 1 + num_bytes)?;
+        let len = input[1..1 + num_bytes]
+            .iter()
+            .fold(0usize, |acc, &b| (acc << 8) | b as usize);
+        Ok((len, 1 + num_bytes))
+    }
+}
+
+fn require_len(input: &[u8], len: usize) -> Result<()> {
+    if input.len() < len {
+        Err(Box::new(MetadataError::UnexpectedEof))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_for_key_value_maps_timestamp_to_utc_time() {
+        assert_eq!(
+            field_for_key_value("timestamp", "260728120000Z"),
+            MetadataField::UtcTime("timestamp=260728120000Z".to_string())
+        );
+    }
+
+    #[test]
+    fn test_field_for_key_value_keeps_key_for_other_fields() {
+        assert_eq!(
+            field_for_key_value("author", "Jane Doe"),
+            MetadataField::Utf8String("author=Jane Doe".to_string())
+        );
+    }
+
+    #[test]
+    fn test_round_trip_single_field() {
+        let mut metadata = MetadataChunk::new();
+        metadata.push(MetadataField::Utf8String("author=Jane Doe".to_string()));
+
+        let bytes = metadata.to_bytes();
+        let decoded = MetadataChunk::parse(&bytes).unwrap();
+        assert_eq!(decoded.fields(), metadata.fields());
+    }
+
+    #[test]
+    fn test_round_trip_multiple_field_types() {
+        let mut metadata = MetadataChunk::new();
+        metadata.push(MetadataField::Utf8String("content-type=text/plain".to_string()));
+        metadata.push(MetadataField::OctetString(vec![1, 2, 3, 4]));
+        metadata.push(MetadataField::UtcTime("260728120000Z".to_string()));
+
+        let bytes = metadata.to_bytes();
+        let decoded = MetadataChunk::parse(&bytes).unwrap();
+        assert_eq!(decoded.fields(), metadata.fields());
+    }
+
+    #[test]
+    fn test_round_trip_long_field_uses_multi_byte_length() {
+        let mut metadata = MetadataChunk::new();
+        metadata.push(MetadataField::OctetString(vec![0xab; 200]));
+
+        let bytes = metadata.to_bytes();
+        assert!(bytes[1] & 0x80 != 0);
+        let decoded = MetadataChunk::parse(&bytes).unwrap();
+        assert_eq!(decoded.fields(), metadata.fields());
+    }
+
+    #[test]
+    fn test_parse_rejects_non_sequence() {
+        assert!(MetadataChunk::parse(&[TAG_UTF8_STRING, 0x00]).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_length_mismatch() {
+        let mut metadata = MetadataChunk::new();
+        metadata.push(MetadataField::Utf8String("x".to_string()));
+        let mut bytes = metadata.to_bytes();
+        bytes[1] += 1; // claim more payload than is actually present
+        assert!(MetadataChunk::parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_tag() {
+        let encoded = encode_tlv(0xff, b"value");
+        let mut sequence = vec![TAG_SEQUENCE];
+        sequence.extend(encode_der_length(encoded.len()));
+        sequence.extend(encoded);
+        assert!(MetadataChunk::parse(&sequence).is_err());
+    }
+}
@@ -0,0 +1,167 @@
+use crate::rlp;
+use crate::Result;
+use std::fmt::Display;
+
+/// Leaves headroom for the RLP `[sequence, total]` header so every part
+/// still fits within a single PNG chunk's maximum length.
+const MAX_PART_DATA_LEN: usize = (1 << 31) - 1 - 64;
+
+#[derive(Debug)]
+pub enum MultipartError {
+    MissingParts { expected: u64, found: usize },
+    InconsistentTotal { sequence: u64, expected: u64, found: u64 },
+    MalformedHeader { item_count: usize },
+}
+
+impl std::error::Error for MultipartError {}
+
+impl Display for MultipartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MultipartError::MissingParts { expected, found } => {
+                write!(f, "Expected {} parts but only found {}", expected, found)
+            }
+            MultipartError::InconsistentTotal {
+                sequence,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Part {} reports {} total parts, expected {}",
+                sequence, found, expected
+            ),
+            MultipartError::MalformedHeader { item_count } => write!(
+                f,
+                "Expected a [sequence, total] header with 2 items but found {}",
+                item_count
+            ),
+        }
+    }
+}
+
+/// Returns true if `data` begins with what looks like a multipart
+/// `[sequence, total]` header: a two-item RLP list. Used to tell split
+/// message parts apart from chunks that merely share a type (e.g. `IDAT`)
+/// but were never split.
+pub fn has_header(data: &[u8]) -> bool {
+    matches!(rlp::decode_list(data), Ok((items, _)) if items.len() == 2)
+}
+
+/// Splits `message` into one or more parts, each prefixed with a compact
+/// RLP-encoded `[sequence, total]` header so `reassemble` can put them
+/// back together regardless of the order they're read back in.
+pub fn split(message: &[u8]) -> Vec<Vec<u8>> {
+    let data_parts: Vec<&[u8]> = if message.is_empty() {
+        vec![&[]]
+    } else {
+        message.chunks(MAX_PART_DATA_LEN).collect()
+    };
+    let total = data_parts.len() as u64;
+    data_parts
+        .iter()
+        .enumerate()
+        .map(|(sequence, data)| {
+            let header = rlp::encode_list(&[rlp::encode_uint(sequence as u64), rlp::encode_uint(total)]);
+            header.into_iter().chain(data.iter().copied()).collect()
+        })
+        .collect()
+}
+
+/// Reassembles a message from its parts, which may arrive in any order.
+/// Each part's header is decoded to recover its `sequence`/`total`, the
+/// parts are sorted by sequence, and the remaining payload bytes are
+/// concatenated.
+pub fn reassemble(parts: Vec<Vec<u8>>) -> Result<Vec<u8>> {
+    let total = parts.len();
+    let mut indexed = Vec::with_capacity(total);
+    for part in parts {
+        let (header, consumed) = rlp::decode_list(&part)?;
+        if header.len() != 2 {
+            return Err(Box::new(MultipartError::MalformedHeader {
+                item_count: header.len(),
+            }));
+        }
+        let sequence = rlp::decode_uint(&header[0]);
+        let declared_total = rlp::decode_uint(&header[1]);
+        if declared_total != total as u64 {
+            return Err(Box::new(MultipartError::InconsistentTotal {
+                sequence,
+                expected: total as u64,
+                found: declared_total,
+            }));
+        }
+        indexed.push((sequence, part[consumed..].to_vec()));
+    }
+    indexed.sort_by_key(|(sequence, _)| *sequence);
+    for (expected, (sequence, _)) in indexed.iter().enumerate() {
+        if *sequence != expected as u64 {
+            let mut distinct: Vec<u64> = indexed
+                .iter()
+                .map(|(sequence, _)| *sequence)
+                .filter(|sequence| *sequence < total as u64)
+                .collect();
+            distinct.dedup();
+            return Err(Box::new(MultipartError::MissingParts {
+                expected: total as u64,
+                found: distinct.len(),
+            }));
+        }
+    }
+    Ok(indexed.into_iter().flat_map(|(_, data)| data).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_small_message_is_single_part() {
+        let parts = split(b"hello");
+        assert_eq!(parts.len(), 1);
+    }
+
+    #[test]
+    fn test_split_reassemble_round_trip() {
+        let message = b"a message split across multiple chunks".to_vec();
+        let parts = split(&message);
+        let reassembled = reassemble(parts).unwrap();
+        assert_eq!(reassembled, message);
+    }
+
+    #[test]
+    fn test_reassemble_is_order_independent() {
+        let message = b"another message".to_vec();
+        let mut parts = split(&message);
+        parts.reverse();
+        let reassembled = reassemble(parts).unwrap();
+        assert_eq!(reassembled, message);
+    }
+
+    #[test]
+    fn test_reassemble_rejects_mismatched_total() {
+        let message = b"hello".to_vec();
+        let mut parts = split(&message);
+        let other_parts = split(b"world");
+        parts.push(other_parts[0].clone());
+        assert!(reassemble(parts).is_err());
+    }
+
+    #[test]
+    fn test_reassemble_rejects_short_list_header_without_panicking() {
+        // `0xc0` is a valid RLP encoding of an empty list, so `decode_list`
+        // succeeds but leaves no `sequence`/`total` items to index.
+        let empty_list_header = vec![0xc0];
+        assert!(reassemble(vec![empty_list_header]).is_err());
+
+        // `0xc1 0x05` is a valid one-item list.
+        let one_item_header = vec![0xc1, 0x05];
+        assert!(reassemble(vec![one_item_header]).is_err());
+    }
+
+    #[test]
+    fn test_has_header_distinguishes_real_headers_from_plain_data() {
+        let parts = split(b"hello");
+        assert!(has_header(&parts[0]));
+        assert!(!has_header(b"plain IDAT-like data"));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::Result;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub enum RlpError {
+    UnexpectedEof,
+    ExpectedString(u8),
+    ExpectedList(u8),
+}
+
+impl std::error::Error for RlpError {}
+
+impl Display for RlpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RlpError::UnexpectedEof => write!(f, "Unexpected end of RLP input"),
+            RlpError::ExpectedString(prefix) => {
+                write!(f, "Expected an RLP string but found list prefix {:#x}", prefix)
+            }
+            RlpError::ExpectedList(prefix) => {
+                write!(f, "Expected an RLP list but found string prefix {:#x}", prefix)
+            }
+        }
+    }
+}
+
+/// Encodes a non-negative integer as its shortest big-endian byte string,
+/// then as an RLP string (zero encodes as the empty string).
+pub fn encode_uint(n: u64) -> Vec<u8> {
+    let bytes = n.to_be_bytes();
+    let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    encode_string(&bytes[first_nonzero..])
+}
+
+/// Encodes a byte string per the RLP string rules.
+pub fn encode_string(data: &[u8]) -> Vec<u8> {
+    if data.len() == 1 && data[0] < 0x80 {
+        vec![data[0]]
+    } else if data.len() <= 55 {
+        let mut out = Vec::with_capacity(1 + data.len());
+        out.push(0x80 + data.len() as u8);
+        out.extend_from_slice(data);
+        out
+    } else {
+        let len_bytes = shortest_be_bytes(data.len() as u64);
+        let mut out = Vec::with_capacity(1 + len_bytes.len() + data.len());
+        out.push(0xb7 + len_bytes.len() as u8);
+        out.extend_from_slice(&len_bytes);
+        out.extend_from_slice(data);
+        out
+    }
+}
+
+/// Wraps the concatenation of already-encoded `items` in an RLP list
+/// header.
+pub fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload: Vec<u8> = items.concat();
+    if payload.len() <= 55 {
+        let mut out = Vec::with_capacity(1 + payload.len());
+        out.push(0xc0 + payload.len() as u8);
+        out.extend(payload);
+        out
+    } else {
+        let len_bytes = shortest_be_bytes(payload.len() as u64);
+        let mut out = Vec::with_capacity(1 + len_bytes.len() + payload.len());
+        out.push(0xf7 + len_bytes.len() as u8);
+        out.extend(len_bytes);
+        out.extend(payload);
+        out
+    }
+}
+
+/// Decodes a single RLP list at the start of `input`, returning its items
+/// as raw byte strings along with how many bytes of `input` the encoding
+/// consumed. Any trailing bytes after the list are left for the caller.
+pub fn decode_list(input: &[u8]) -> Result<(Vec<Vec<u8>>, usize)> {
+    if input.is_empty() {
+        return Err(Box::new(RlpError::UnexpectedEof));
+    }
+    let prefix = input[0];
+    let (payload_start, payload_len) = if (0xc0..=0xf7).contains(&prefix) {
+        (1, (prefix - 0xc0) as usize)
+    } else if prefix > 0xf7 {
+        let len_of_len = (prefix - 0xf7) as usize;
+        require_len(input, 1 + len_of_len)?;
+        let len = decode_uint(&input[1..1 + len_of_len]) as usize;
+        (1 + len_of_len, len)
+    } else {
+        return Err(Box::new(RlpError::ExpectedList(prefix)));
+    };
+    require_len(input, payload_start + payload_len)?;
+    let payload = &input[payload_start..payload_start + payload_len];
+
+    let mut items = Vec::new();
+    let mut offset = 0;
+    while offset < payload.len() {
+        let (item, consumed) = decode_string(&payload[offset..])?;
+        items.push(item);
+        offset += consumed;
+    }
+    Ok((items, payload_start + payload_len))
+}
+
+fn decode_string(input: &[u8]) -> Result<(Vec<u8>, usize)> {
+    let prefix = input[0];
+    if prefix < 0x80 {
+        Ok((vec![prefix], 1))
+    } else if prefix <= 0xb7 {
+        let len = (prefix - 0x80) as usize;
+        require_len(input, 1 + len)?;
+        Ok((input[1..1 + len].to_vec(), 1 + len))
+    } else if prefix <= 0xbf {
+        let len_of_len = (prefix - 0xb7) as usize;
+        require_len(input, 1 + len_of_len)?;
+        let len = decode_uint(&input[1..1 + len_of_len]) as usize;
+        require_len(input, 1 + len_of_len + len)?;
+        Ok((
+            input[1 + len_of_len..1 + len_of_len + len].to_vec(),
+            1 + len_of_len + len,
+        ))
+    } else {
+        Err(Box::new(RlpError::ExpectedString(prefix)))
+    }
+}
+
+fn require_len(input: &[u8], len: usize) -> Result<()> {
+    if input.len() < len {
+        Err(Box::new(RlpError::UnexpectedEof))
+    } else {
+        Ok(())
+    }
+}
+
+fn shortest_be_bytes(n: u64) -> Vec<u8> {
+    let bytes = n.to_be_bytes();
+    let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    bytes[first_nonzero..].to_vec()
+}
+
+/// Decodes a byte string produced by `encode_uint` back into a `u64`.
+pub fn decode_uint(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_uint_single_byte() {
+        assert_eq!(encode_uint(0), vec![0x80]);
+        assert_eq!(encode_uint(5), vec![0x05]);
+        assert_eq!(encode_uint(127), vec![0x7f]);
+    }
+
+    #[test]
+    fn test_encode_uint_multi_byte() {
+        assert_eq!(encode_uint(128), vec![0x81, 0x80]);
+        assert_eq!(encode_uint(1000), vec![0x82, 0x03, 0xe8]);
+    }
+
+    #[test]
+    fn test_encode_string_long() {
+        let data = vec![b'a'; 56];
+        let encoded = encode_string(&data);
+        assert_eq!(encoded[0], 0xb7 + 1);
+        assert_eq!(encoded[1], 56);
+    }
+
+    #[test]
+    fn test_encode_decode_list_round_trip() {
+        let list = encode_list(&[encode_uint(3), encode_uint(7)]);
+        let (items, consumed) = decode_list(&list).unwrap();
+        assert_eq!(consumed, list.len());
+        assert_eq!(decode_uint(&items[0]), 3);
+        assert_eq!(decode_uint(&items[1]), 7);
+    }
+
+    #[test]
+    fn test_decode_list_leaves_trailing_bytes() {
+        let mut list = encode_list(&[encode_uint(1), encode_uint(2)]);
+        list.extend_from_slice(b"payload");
+        let (items, consumed) = decode_list(&list).unwrap();
+        assert_eq!(decode_uint(&items[0]), 1);
+        assert_eq!(decode_uint(&items[1]), 2);
+        assert_eq!(&list[consumed..], b"payload");
+    }
+
+    #[test]
+    fn test_decode_list_rejects_truncated_input() {
+        let list = encode_list(&[encode_uint(1), encode_uint(2)]);
+        assert!(decode_list(&list[..list.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_decode_list_rejects_string_prefix() {
+        let encoded = encode_string(b"not a list");
+        assert!(decode_list(&encoded).is_err());
+    }
+}